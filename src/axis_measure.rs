@@ -1,12 +1,9 @@
 use druid::{Cursor, EventCtx, Point, Rect, Selector, Size, Data};
-use float_ord::FloatOrd;
-use std::collections::BTreeMap;
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 use crate::config::{DEFAULT_COL_HEADER_HEIGHT, DEFAULT_ROW_HEADER_WIDTH};
 use TableAxis::*;
 use crate::Remap;
-use crate::data::{RemapDetails, SortSpec};
 use std::ops::{Add, Sub, RangeInclusive};
 use std::iter::Map;
 
@@ -84,6 +81,18 @@ pub struct VisIdx(pub(crate) usize);
 #[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Data)]
 pub struct LogIdx(pub(crate) usize);
 
+/// The placement of a single cell within an axis: its visible and logical
+/// indices together with the pixel extent (`origin`, `length`) it occupies.
+/// Yielded by [`AxisMeasure::cells_in_pixel_range`] so rendering code can lay
+/// out a scroll viewport without re-querying offsets per cell.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CellLayout {
+    pub vis: VisIdx,
+    pub log: LogIdx,
+    pub origin: f64,
+    pub length: f64,
+}
+
 impl VisIdx{
     // Todo work out how to support custom range
     pub fn range_inc_iter(from_inc: VisIdx, to_inc: VisIdx) -> Map<RangeInclusive<usize>, fn(usize) -> VisIdx> {
@@ -119,6 +128,33 @@ pub trait AxisMeasure: Clone {
     fn set_pixel_length_for_vis(&mut self, idx: VisIdx, length: f64) -> f64;
     fn can_resize(&self, idx: VisIdx) -> bool;
 
+    /// Lay out exactly the cells whose extent intersects the pixel window
+    /// `[p0, p1]`, in ascending visible order. Partially clipped first and last
+    /// cells are included. The default walks the existing primitives; `log` is
+    /// the visible index unless an implementation resolves it through its
+    /// `Remap` (see [`StoredAxisMeasure`]).
+    fn cells_in_pixel_range(&self, p0: f64, p1: f64) -> impl Iterator<Item = CellLayout> + '_ {
+        let (start, end) = self.vis_range_from_pixels(p0, p1);
+        // `vis_range_from_pixels` starts at the cell containing `p0`; back up one so
+        // a cell whose right edge touches `p0` exactly survives the strict `< p0`
+        // filter below, keeping the boundary convention identical across all impls.
+        let start = if start.0 > 0 { start - 1 } else { start };
+        VisIdx::range_inc_iter(start, end).filter_map(move |vis| {
+            let origin = self.first_pixel_from_vis(vis)?;
+            let length = self.pixels_length_for_vis(vis)?;
+            if origin > p1 || origin + length < p0 {
+                None
+            } else {
+                Some(CellLayout {
+                    vis,
+                    log: LogIdx(vis.0),
+                    origin,
+                    length,
+                })
+            }
+        })
+    }
+
     fn pixel_near_border(&self, pixel: f64) -> Option<VisIdx> {
         let idx = self.vis_from_pixel(pixel)?;
         let idx_border_middle = self.first_pixel_from_vis(idx).unwrap_or(0.) - self.border() / 2.;
@@ -220,6 +256,38 @@ impl AxisMeasure for FixedAxisMeasure {
     fn can_resize(&self, _idx: VisIdx) -> bool {
         false
     }
+
+    fn cells_in_pixel_range(&self, p0: f64, p1: f64) -> impl Iterator<Item = CellLayout> + '_ {
+        let fpu = self.full_pixels_per_unit();
+        let len = self.len;
+        let end = if p1 < 0. {
+            0
+        } else {
+            ((p1 / fpu).floor() as usize + 1).min(len)
+        };
+        // Back up one cell from the one containing `p0` so a cell whose right edge
+        // touches `p0` exactly reaches the (strict) `< p0` filter and is included,
+        // matching the trait default and `StoredAxisMeasure`.
+        let start = if p0 <= 0. {
+            0
+        } else {
+            ((p0 / fpu).floor() as usize).saturating_sub(1).min(end)
+        };
+        (start..end).filter_map(move |i| {
+            let origin = (i as f64) * fpu;
+            let length = self.pixels_per_unit;
+            if origin + length < p0 || origin > p1 {
+                None
+            } else {
+                Some(CellLayout {
+                    vis: VisIdx(i),
+                    log: LogIdx(i),
+                    origin,
+                    length,
+                })
+            }
+        })
+    }
 }
 
 #[derive(Clone)]
@@ -227,8 +295,10 @@ pub struct StoredAxisMeasure {
     remap: Remap,
     log_pix_lengths: Vec<f64>,
     vis_pix_lengths: Vec<f64>,
-    first_pixels: BTreeMap<VisIdx, f64>, // TODO newtypes
-    pixels_to_vis: BTreeMap<FloatOrd<f64>, VisIdx>,
+    // Fenwick tree (binary indexed tree) over the per-vis extents (length + border),
+    // indexed 1..=n. Gives O(log n) prefix sums and point updates so interactive
+    // resizes no longer rebuild the whole axis.
+    tree: Vec<f64>,
     default_pixels: f64,
     border: f64,
     total_pixel_length: f64,
@@ -251,8 +321,9 @@ macro_rules! debug_fn {
 
 impl Debug for StoredAxisMeasure {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-        let fp = &self.first_pixels;
-        let pti = &self.pixels_to_vis;
+        let fp = self.vis_pix_lengths.iter().enumerate().map(|(idx, _)| {
+            (VisIdx(idx), self.first_pixel_from_vis(VisIdx(idx)).unwrap_or(0.))
+        });
         fmt.debug_struct("StoredAxisMeasure")
             .field("log_pix_lengths", &self.log_pix_lengths)
             .field("vis_pix_lengths", &self.vis_pix_lengths)
@@ -261,14 +332,7 @@ impl Debug for StoredAxisMeasure {
             .field("total_pixel_length", &self.total_pixel_length)
             .field(
                 "first_pixels",
-                debug_fn!(|f| f.debug_map().entries(fp.iter()).finish()),
-            )
-            .field(
-                "pixels_to_index",
-                debug_fn!(|f| f
-                    .debug_map()
-                    .entries(pti.iter().map(|(k, v)| (k.0, v)))
-                    .finish()),
+                debug_fn!(|f| f.debug_map().entries(fp.clone()).finish()),
             )
             .finish()
     }
@@ -280,8 +344,7 @@ impl StoredAxisMeasure {
             remap: Remap::Pristine,
             log_pix_lengths: Default::default(),
             vis_pix_lengths: Default::default(),
-            first_pixels: Default::default(),
-            pixels_to_vis: Default::default(),
+            tree: Default::default(),
             default_pixels,
             border: 0.,
             total_pixel_length: 0.,
@@ -289,26 +352,78 @@ impl StoredAxisMeasure {
     }
 
     fn build_maps(&mut self) {
-        let mut cur = 0.;
+        // Lay the axis out by walking the visible indices and asking the remap for
+        // the logical slot behind each one. This is uniform across a pristine axis,
+        // a sort, a projection (subset + reorder), and a sort composed inside a
+        // projection, so there is no per-variant special casing here. A projection
+        // is caller-supplied, so a stale `LogIdx` is clamped to a zero extent via
+        // `get` rather than panicking on a direct index.
+        let count = self.remap.len(self.log_pix_lengths.len());
         self.vis_pix_lengths.clear();
-        match &self.remap {
-            Remap::Selected(RemapDetails::Full(vis_to_log))=>{
-                for log_idx in vis_to_log{
-                    self.vis_pix_lengths.push( self.log_pix_lengths[log_idx.0] );
-                }
-            }
-            _=>self.vis_pix_lengths.extend_from_slice( &self.log_pix_lengths )
+        self.vis_pix_lengths.reserve(count);
+        for vis in 0..count {
+            let length = self
+                .remap
+                .get_log_idx(VisIdx(vis))
+                .and_then(|log_idx| self.log_pix_lengths.get(log_idx.0).copied())
+                .unwrap_or(0.);
+            self.vis_pix_lengths.push(length);
         }
 
+        self.build_tree();
+    }
 
-        self.first_pixels.clear();
-        self.pixels_to_vis.clear();
-        for (idx, pixels) in self.vis_pix_lengths.iter().enumerate() {
-            self.first_pixels.insert(VisIdx(idx), cur);
-            self.pixels_to_vis.insert(FloatOrd(cur), VisIdx(idx));
-            cur += pixels + self.border;
+    // Rebuild the Fenwick tree from the current per-vis lengths. O(n), run on
+    // every `set_axis_properties` call (not on an individual resize).
+    fn build_tree(&mut self) {
+        let n = self.vis_pix_lengths.len();
+        self.tree = vec![0.; n + 1];
+        for idx in 0..n {
+            self.tree_add(idx, self.vis_pix_lengths[idx] + self.border);
         }
-        self.total_pixel_length = cur;
+        self.total_pixel_length = self.prefix_sum(n);
+    }
+
+    // Add `delta` to the extent at vis index `idx`.
+    fn tree_add(&mut self, idx: usize, delta: f64) {
+        let n = self.vis_pix_lengths.len();
+        let mut k = idx + 1;
+        while k <= n {
+            self.tree[k] += delta;
+            k += k & k.wrapping_neg();
+        }
+    }
+
+    // Sum of the extents over the half-open vis range `[0, count)`.
+    fn prefix_sum(&self, count: usize) -> f64 {
+        let mut sum = 0.;
+        let mut k = count;
+        while k > 0 {
+            sum += self.tree[k];
+            k -= k & k.wrapping_neg();
+        }
+        sum
+    }
+
+    // Largest vis index whose first pixel is <= `pixel`, via binary lifting on the
+    // tree. Returns `None` when there are no cells or `pixel` precedes the axis.
+    fn vis_from_pixel_tree(&self, pixel: f64) -> Option<VisIdx> {
+        let n = self.vis_pix_lengths.len();
+        if n == 0 || pixel < 0. {
+            return None;
+        }
+        let mut pos = 0usize;
+        let mut remaining = pixel;
+        let mut bit = n.next_power_of_two();
+        while bit > 0 {
+            let next = pos + bit;
+            if next <= n && self.tree[next] <= remaining {
+                pos = next;
+                remaining -= self.tree[pos];
+            }
+            bit >>= 1;
+        }
+        Some(VisIdx(pos.min(n - 1)))
     }
 }
 
@@ -340,10 +455,7 @@ impl AxisMeasure for StoredAxisMeasure {
     }
 
     fn vis_from_pixel(&self, pixel: f64) -> Option<VisIdx> {
-        self.pixels_to_vis
-            .range(..=FloatOrd(pixel))
-            .next_back()
-            .map(|(_, v)| *v)
+        self.vis_from_pixel_tree(pixel)
     }
 
     fn vis_range_from_pixels(&self, p0: f64, p1: f64) -> (VisIdx, VisIdx) {
@@ -355,7 +467,11 @@ impl AxisMeasure for StoredAxisMeasure {
     }
 
     fn first_pixel_from_vis(&self, idx: VisIdx) -> Option<f64> {
-        self.first_pixels.get(&idx).copied()
+        if idx.0 < self.vis_pix_lengths.len() {
+            Some(self.prefix_sum(idx.0))
+        } else {
+            None
+        }
     }
 
     fn pixels_length_for_vis(&self, idx: VisIdx) -> Option<f64> {
@@ -363,7 +479,7 @@ impl AxisMeasure for StoredAxisMeasure {
     }
 
     fn set_far_pixel_for_vis(&mut self, idx: VisIdx, pixel: f64) -> f64 {
-        let length = f64::max(0., pixel - *self.first_pixels.get(&idx).unwrap_or(&0.));
+        let length = f64::max(0., pixel - self.first_pixel_from_vis(idx).unwrap_or(0.));
         self.set_pixel_length_for_vis(idx, length)
     }
 
@@ -372,7 +488,13 @@ impl AxisMeasure for StoredAxisMeasure {
         if let Some(log_idx) = self.remap.get_log_idx(vis_idx) {
             if let Some(place) = self.log_pix_lengths.get_mut(log_idx.0) {
                 *place = length;
-                self.build_maps(); // TODO : modify efficiently instead of rebuilding
+                // One O(log n) point update rather than rebuilding the whole axis.
+                if let Some(old) = self.vis_pix_lengths.get_mut(vis_idx.0) {
+                    let delta = length - *old;
+                    *old = length;
+                    self.tree_add(vis_idx.0, delta);
+                    self.total_pixel_length += delta;
+                }
                 return length
             }
         }
@@ -382,15 +504,55 @@ impl AxisMeasure for StoredAxisMeasure {
     fn can_resize(&self, _idx: VisIdx) -> bool {
         true
     }
+
+    fn cells_in_pixel_range(&self, p0: f64, p1: f64) -> impl Iterator<Item = CellLayout> + '_ {
+        let n = self.vis_pix_lengths.len();
+        // `vis_from_pixel` lands on the cell containing `p0`; back up one so a cell
+        // whose right edge touches `p0` exactly is still offered to the (strict)
+        // `< p0` filter below, matching the trait default and `FixedAxisMeasure`.
+        let start = if n == 0 {
+            0
+        } else {
+            self.vis_from_pixel(p0).map_or(0, |v| v.0.saturating_sub(1))
+        };
+        let mut pos = start;
+        let mut origin = self.first_pixel_from_vis(VisIdx(start)).unwrap_or(0.);
+        std::iter::from_fn(move || {
+            while pos < n {
+                let vis = VisIdx(pos);
+                let length = self.vis_pix_lengths[pos];
+                let cell_origin = origin;
+                pos += 1;
+                origin += length + self.border;
+                if cell_origin > p1 {
+                    return None;
+                }
+                if cell_origin + length < p0 {
+                    continue;
+                }
+                let log = self.remap.get_log_idx(vis).unwrap_or(LogIdx(vis.0));
+                return Some(CellLayout {
+                    vis,
+                    log,
+                    origin: cell_origin,
+                    length,
+                });
+            }
+            None
+        })
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::{AxisMeasure, FixedAxisMeasure, StoredAxisMeasure, Remap};
+    use crate::data::RemapDetails;
+    use crate::table::TableProjection;
+    use crate::TableAxis::Columns;
     use float_ord::FloatOrd;
     use std::collections::HashSet;
     use std::fmt::Debug;
-    use crate::axis_measure::VisIdx;
+    use crate::axis_measure::{CellLayout, LogIdx, VisIdx};
 
     #[test]
     fn fixed_axis() {
@@ -439,4 +601,201 @@ mod test {
         assert_eq!(ax.set_far_pixel_for_vis(VisIdx(1), 109.), 9.);
         assert_eq!(ax.total_pixel_length(), 260.0)
     }
+
+    #[test]
+    fn fixed_cells_in_pixel_range() {
+        let mut ax = FixedAxisMeasure::new(100.0);
+        ax.set_axis_properties(0.0, 10, &Remap::Pristine);
+
+        // A window that clips the first and last cells partially.
+        let cells: Vec<CellLayout> = ax.cells_in_pixel_range(150.0, 420.0).collect();
+        assert_eq!(
+            cells,
+            vec![
+                CellLayout { vis: VisIdx(1), log: LogIdx(1), origin: 100.0, length: 100.0 },
+                CellLayout { vis: VisIdx(2), log: LogIdx(2), origin: 200.0, length: 100.0 },
+                CellLayout { vis: VisIdx(3), log: LogIdx(3), origin: 300.0, length: 100.0 },
+                CellLayout { vis: VisIdx(4), log: LogIdx(4), origin: 400.0, length: 100.0 },
+            ]
+        );
+
+        // Empty window off the end yields nothing.
+        assert!(ax.cells_in_pixel_range(5000.0, 6000.0).next().is_none());
+    }
+
+    #[test]
+    fn stored_cells_in_pixel_range() {
+        let mut ax = StoredAxisMeasure::new(100.0);
+        ax.set_axis_properties(0.0, 5, &Remap::Pristine);
+        ax.set_pixel_length_for_vis(VisIdx(1), 50.0); // now: 0,100,150,250,350
+
+        let cells: Vec<CellLayout> = ax.cells_in_pixel_range(120.0, 260.0).collect();
+        assert_eq!(
+            cells,
+            vec![
+                CellLayout { vis: VisIdx(1), log: LogIdx(1), origin: 100.0, length: 50.0 },
+                CellLayout { vis: VisIdx(2), log: LogIdx(2), origin: 150.0, length: 100.0 },
+                CellLayout { vis: VisIdx(3), log: LogIdx(3), origin: 250.0, length: 100.0 },
+            ]
+        );
+
+        // Empty range collapsed to a point inside a cell still reports the cell it lands in.
+        let at_point: Vec<CellLayout> = ax.cells_in_pixel_range(175.0, 175.0).collect();
+        assert_eq!(at_point, vec![CellLayout { vis: VisIdx(2), log: LogIdx(2), origin: 150.0, length: 100.0 }]);
+
+        // An axis with no cells yields nothing.
+        let mut empty = StoredAxisMeasure::new(10.0);
+        empty.set_axis_properties(0.0, 0, &Remap::Pristine);
+        assert!(empty.cells_in_pixel_range(0.0, 100.0).next().is_none());
+    }
+
+    #[test]
+    fn cells_in_pixel_range_boundary_touch_agrees() {
+        // A cell whose right edge lands exactly on p0 shares the point p0 with the
+        // window and must be included identically by Fixed and Stored.
+        let mut fixed = FixedAxisMeasure::new(100.0);
+        fixed.set_axis_properties(0.0, 5, &Remap::Pristine);
+        let mut stored = StoredAxisMeasure::new(100.0);
+        stored.set_axis_properties(0.0, 5, &Remap::Pristine);
+
+        let fixed_cells: Vec<CellLayout> = fixed.cells_in_pixel_range(200.0, 350.0).collect();
+        let stored_cells: Vec<CellLayout> = stored.cells_in_pixel_range(200.0, 350.0).collect();
+        assert_eq!(fixed_cells, stored_cells);
+        assert_eq!(
+            fixed_cells,
+            vec![
+                CellLayout { vis: VisIdx(1), log: LogIdx(1), origin: 100.0, length: 100.0 },
+                CellLayout { vis: VisIdx(2), log: LogIdx(2), origin: 200.0, length: 100.0 },
+                CellLayout { vis: VisIdx(3), log: LogIdx(3), origin: 300.0, length: 100.0 },
+            ]
+        );
+    }
+
+    // Five logical cells with distinct lengths so we can tell them apart.
+    fn distinct_length_axis() -> StoredAxisMeasure {
+        let mut ax = StoredAxisMeasure::new(10.0);
+        ax.set_axis_properties(0.0, 5, &Remap::Pristine);
+        for i in 0..5 {
+            ax.set_pixel_length_for_vis(VisIdx(i), 10.0 + 10.0 * (i as f64)); // 10,20,30,40,50
+        }
+        ax
+    }
+
+    #[test]
+    fn stored_axis_projection() {
+        let mut ax = distinct_length_axis();
+
+        // Hide logical 0 and 2, present the rest reordered as [4, 1, 3] via the
+        // runtime selector a caller would use on the table.
+        let mut projections = TableProjection::new();
+        projections.set_visible_columns(vec![LogIdx(4), LogIdx(1), LogIdx(3)]);
+        let projection = projections.remap(Columns, Remap::Pristine);
+        ax.set_axis_properties(0.0, 5, &projection);
+
+        assert_eq!(ax.pixels_length_for_vis(VisIdx(0)), Some(50.0));
+        assert_eq!(ax.pixels_length_for_vis(VisIdx(1)), Some(20.0));
+        assert_eq!(ax.pixels_length_for_vis(VisIdx(2)), Some(40.0));
+        assert_eq!(ax.pixels_length_for_vis(VisIdx(3)), None); // hidden ones are gone
+        assert_eq!(ax.first_pixel_from_vis(VisIdx(1)), Some(50.0));
+        assert_eq!(ax.first_pixel_from_vis(VisIdx(2)), Some(70.0));
+        assert_eq!(ax.total_pixel_length(), 110.0);
+
+        // Resizing a projected visible index must route to its logical slot (3),
+        // and survive a rebuild under the same projection.
+        ax.set_pixel_length_for_vis(VisIdx(2), 5.0);
+        ax.set_axis_properties(0.0, 5, &projection);
+        assert_eq!(ax.pixels_length_for_vis(VisIdx(2)), Some(5.0));
+        assert_eq!(ax.total_pixel_length(), 75.0);
+    }
+
+    #[test]
+    fn stored_axis_projection_clamps_stale_entry() {
+        let mut ax = distinct_length_axis();
+        // A stale projection referencing a logical index past the end must not
+        // panic; the dangling slot is laid out with zero extent.
+        let projection = Remap::project(vec![LogIdx(1), LogIdx(9), LogIdx(3)]);
+        ax.set_axis_properties(0.0, 5, &projection);
+        assert_eq!(ax.pixels_length_for_vis(VisIdx(0)), Some(20.0));
+        assert_eq!(ax.pixels_length_for_vis(VisIdx(1)), Some(0.0));
+        assert_eq!(ax.pixels_length_for_vis(VisIdx(2)), Some(40.0));
+        assert_eq!(ax.total_pixel_length(), 60.0);
+    }
+
+    #[test]
+    fn stored_axis_sort_within_projection() {
+        let mut ax = distinct_length_axis();
+        // Project onto logical [4, 1, 3] (lengths 50, 20, 40) and sort those three
+        // visible cells by length ascending: visible order 20, 40, 50 maps to the
+        // projected positions [1, 2, 0].
+        let sort = Remap::Selected(RemapDetails::Full(vec![LogIdx(1), LogIdx(2), LogIdx(0)]));
+        let projection = sort.projecting(vec![LogIdx(4), LogIdx(1), LogIdx(3)]);
+        ax.set_axis_properties(0.0, 5, &projection);
+
+        assert_eq!(ax.pixels_length_for_vis(VisIdx(0)), Some(20.0)); // logical 1
+        assert_eq!(ax.pixels_length_for_vis(VisIdx(1)), Some(40.0)); // logical 3
+        assert_eq!(ax.pixels_length_for_vis(VisIdx(2)), Some(50.0)); // logical 4
+        assert_eq!(ax.total_pixel_length(), 110.0);
+
+        // Resize still routes to the correct logical slot through both layers.
+        ax.set_pixel_length_for_vis(VisIdx(0), 5.0); // logical 1
+        ax.set_axis_properties(0.0, 5, &projection);
+        assert_eq!(ax.pixels_length_for_vis(VisIdx(0)), Some(5.0));
+        assert_eq!(ax.total_pixel_length(), 95.0);
+    }
+
+    // Brute-force cumulative offsets, mirroring the pre-Fenwick BTreeMap build, so
+    // the tree-based measure can be checked against a known-good reference.
+    fn reference_first_pixels(ax: &StoredAxisMeasure, len: usize) -> Vec<f64> {
+        let mut cur = 0.;
+        let mut out = Vec::with_capacity(len);
+        for idx in 0..len {
+            out.push(cur);
+            cur += ax.pixels_length_for_vis(VisIdx(idx)).unwrap() + ax.border();
+        }
+        out
+    }
+
+    #[test]
+    fn stored_axis_tree_parity() {
+        let len = 37usize;
+        let mut ax = StoredAxisMeasure::new(20.);
+        ax.set_axis_properties(1.0, len, &Remap::Pristine);
+
+        // Deterministic pseudo-random resize sequence (no dependency on `rand`).
+        let mut state = 0x1234_5678u64;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as usize
+        };
+
+        for _ in 0..500 {
+            let idx = VisIdx(next() % len);
+            let length = (next() % 200) as f64;
+            ax.set_pixel_length_for_vis(idx, length);
+
+            let reference = reference_first_pixels(&ax, len);
+            for (i, expected) in reference.iter().enumerate() {
+                assert_eq!(ax.first_pixel_from_vis(VisIdx(i)), Some(*expected));
+            }
+            assert_eq!(ax.first_pixel_from_vis(VisIdx(len)), None);
+            assert_eq!(ax.total_pixel_length(), *reference.last().unwrap() + ax.pixels_length_for_vis(VisIdx(len - 1)).unwrap() + ax.border());
+
+            // vis_from_pixel must agree with a linear scan of the reference offsets.
+            for sample in [-1i32, 0, 17, 123, 999, 5000] {
+                let pixel = sample as f64;
+                let expected = if pixel < 0. {
+                    None
+                } else {
+                    let mut found = Some(VisIdx(0));
+                    for (i, first) in reference.iter().enumerate() {
+                        if *first <= pixel {
+                            found = Some(VisIdx(i));
+                        }
+                    }
+                    found
+                };
+                assert_eq!(ax.vis_from_pixel(pixel), expected);
+            }
+        }
+    }
 }
@@ -0,0 +1,95 @@
+use crate::axis_measure::{LogIdx, VisIdx};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SortSpec {
+    pub idx: usize,
+    pub direction: SortDirection,
+}
+
+impl SortSpec {
+    pub fn new(idx: usize, direction: SortDirection) -> SortSpec {
+        SortSpec { idx, direction }
+    }
+}
+
+/// The concrete mapping for a [`Remap::Selected`] axis: a full permutation of
+/// the logical indices, as produced by a sort.
+#[derive(Clone, Debug)]
+pub enum RemapDetails {
+    Full(Vec<LogIdx>),
+}
+
+impl RemapDetails {
+    fn get_log_idx(&self, vis: VisIdx) -> Option<LogIdx> {
+        match self {
+            RemapDetails::Full(vis_to_log) => vis_to_log.get(vis.0).copied(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            RemapDetails::Full(vis_to_log) => vis_to_log.len(),
+        }
+    }
+}
+
+/// How visible indices along an axis map onto logical ones.
+///
+/// `Project` stacks a chosen subset/order of logical indices on top of an inner
+/// remap, with the inner remap applied *within* the projected subset — so a sort
+/// composed inside a projection sorts only the visible rows/columns.
+#[derive(Clone, Debug)]
+pub enum Remap {
+    Pristine,
+    Selected(RemapDetails),
+    Project(Vec<LogIdx>, Box<Remap>),
+}
+
+impl Remap {
+    /// Project onto `projection` (a chosen, possibly reordered subset of logical
+    /// indices), showing the subset in logical-by-projection order.
+    pub fn project(projection: Vec<LogIdx>) -> Remap {
+        Remap::Project(projection, Box::new(Remap::Pristine))
+    }
+
+    /// Stack `projection` on top of `self`, so the existing remap (typically a
+    /// sort) applies within the projected subset.
+    pub fn projecting(self, projection: Vec<LogIdx>) -> Remap {
+        Remap::Project(projection, Box::new(self))
+    }
+
+    /// The logical index behind visible index `vis`, or `None` when `vis` is out
+    /// of range. A projection entry is returned as is; it is not checked against
+    /// the axis's logical length.
+    pub fn get_log_idx(&self, vis: VisIdx) -> Option<LogIdx> {
+        match self {
+            Remap::Pristine => Some(LogIdx(vis.0)),
+            Remap::Selected(details) => details.get_log_idx(vis),
+            Remap::Project(projection, inner) => {
+                // The inner remap orders positions *within* the projected subset;
+                // that position then selects an entry from the projection.
+                let within = inner.get_log_idx(vis)?;
+                projection.get(within.0).copied()
+            }
+        }
+    }
+
+    /// Number of visible indices given `logical_len` logical ones.
+    pub fn len(&self, logical_len: usize) -> usize {
+        match self {
+            Remap::Pristine => logical_len,
+            Remap::Selected(details) => details.len(),
+            Remap::Project(projection, _) => projection.len(),
+        }
+    }
+
+    pub fn is_empty(&self, logical_len: usize) -> bool {
+        self.len(logical_len) == 0
+    }
+}
@@ -0,0 +1,90 @@
+use crate::axis_measure::{LogIdx, TableAxis};
+use crate::data::Remap;
+use TableAxis::*;
+
+/// Runtime selection of which logical indices are visible along one axis, and in
+/// what order. Mirrors ndarray's `select(Axis, &indices)`: a `None` projection
+/// shows every logical index in order, while a `Some(list)` shows exactly the
+/// listed indices, in the listed order (so indices can be hidden or reordered).
+///
+/// The selection is composed *on top of* whatever remap the axis already carries
+/// (e.g. an active sort) via [`AxisProjection::remap`], so sorting happens within
+/// the projected subset.
+#[derive(Clone, Debug, Default)]
+pub struct AxisProjection {
+    projection: Option<Vec<LogIdx>>,
+}
+
+impl AxisProjection {
+    pub fn new() -> AxisProjection {
+        AxisProjection { projection: None }
+    }
+
+    /// Show every logical index in logical order.
+    pub fn show_all(&mut self) {
+        self.projection = None;
+    }
+
+    /// Show exactly `visible`, in the given order (hiding everything else).
+    pub fn select(&mut self, visible: impl IntoIterator<Item = LogIdx>) {
+        self.projection = Some(visible.into_iter().collect());
+    }
+
+    /// Whether a projection is currently applied.
+    pub fn is_projected(&self) -> bool {
+        self.projection.is_some()
+    }
+
+    /// Stack this selection on top of `inner`, returning the remap the axis
+    /// measure should use. With no projection set, `inner` is returned unchanged.
+    pub fn remap(&self, inner: Remap) -> Remap {
+        match &self.projection {
+            None => inner,
+            Some(projection) => inner.projecting(projection.clone()),
+        }
+    }
+}
+
+/// The per-axis visible-index selectors a table owns. Callers mutate these at
+/// runtime to hide, show, or reorder columns and rows; the table feeds the
+/// resulting [`Remap`] (composed with any active sort) into each axis measure.
+#[derive(Clone, Debug, Default)]
+pub struct TableProjection {
+    columns: AxisProjection,
+    rows: AxisProjection,
+}
+
+impl TableProjection {
+    pub fn new() -> TableProjection {
+        TableProjection::default()
+    }
+
+    pub fn axis(&self, axis: TableAxis) -> &AxisProjection {
+        match axis {
+            Columns => &self.columns,
+            Rows => &self.rows,
+        }
+    }
+
+    pub fn axis_mut(&mut self, axis: TableAxis) -> &mut AxisProjection {
+        match axis {
+            Columns => &mut self.columns,
+            Rows => &mut self.rows,
+        }
+    }
+
+    /// Set the visible columns (in order); pass the logical indices to keep.
+    pub fn set_visible_columns(&mut self, visible: impl IntoIterator<Item = LogIdx>) {
+        self.columns.select(visible);
+    }
+
+    /// Set the visible rows (in order); pass the logical indices to keep.
+    pub fn set_visible_rows(&mut self, visible: impl IntoIterator<Item = LogIdx>) {
+        self.rows.select(visible);
+    }
+
+    /// The remap for `axis`, projecting the chosen subset over `inner` (any sort).
+    pub fn remap(&self, axis: TableAxis, inner: Remap) -> Remap {
+        self.axis(axis).remap(inner)
+    }
+}